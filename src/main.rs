@@ -1,18 +1,29 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
 use druid::piet::{Text, TextLayout, TextLayoutBuilder};
-use druid::widget::{prelude::*, TextBox, ValueTextBox};
+use druid::widget::{prelude::*, Controller, TextBox, ValueTextBox};
 use druid::{
     kurbo::{Circle, CircleSegment, Line},
     text::{Formatter, Selection, Validation, ValidationError},
-    widget::{Button, Flex, Painter, SizedBox},
-    AppLauncher, Color, Data, Env, Widget, WindowDesc,
+    widget::{Flex, SizedBox, ViewSwitcher},
+    AppLauncher, Color, Data, Env, Selector, Widget, WindowDesc,
 };
 use druid::{Lens, WidgetExt};
 
+use chrono::{Local, Timelike};
+use serde::{Deserialize, Serialize};
+
 use std::f64::consts::PI;
 use std::fmt;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
+
+/// Toggles the live ticking mode of the clock face on and off.
+const TOGGLE_LIVE: Selector = Selector::new("clock.toggle-live");
+
+/// Emitted by the time-picker overlay carrying the time the user confirmed.
+const PICK_TIME: Selector<Time> = Selector::new("clock.pick-time");
 
 const WINDOW_SIZE: f64 = 1400.;
 
@@ -20,6 +31,69 @@ const WINDOW_SIZE: f64 = 1400.;
 struct Time {
     hours: u8,
     minutes: u8,
+    seconds: u8,
+}
+
+/// All colors used to paint the clock face, so they can be edited at runtime
+/// instead of being baked into the `Painter` as constants. Mirrors the
+/// single-struct-of-named-color-fields approach used by the satscalc editor,
+/// including dedicated `button_hover`/`button_click` tints.
+#[derive(Clone, Data, Lens)]
+struct Theme {
+    background: Color,
+    tick: Color,
+    hour_hand: Color,
+    minute_hand: Color,
+    second_hand: Color,
+    button_hover: Color,
+    button_click: Color,
+    palette: Arc<Vec<Color>>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let orange = Color::rgb8(240, 128, 0);
+        Theme {
+            background: Color::WHITE,
+            tick: Color::BLACK,
+            hour_hand: Color::BLACK,
+            minute_hand: Color::BLACK,
+            second_hand: Color::BLACK,
+            button_hover: Color::rgb8(90, 90, 90),
+            button_click: Color::rgb8(60, 60, 60),
+            palette: Arc::new(vec![
+                Color::RED,
+                orange,
+                Color::YELLOW,
+                Color::GREEN,
+                Color::BLUE,
+                Color::PURPLE,
+                Color::RED,
+                orange,
+                Color::YELLOW,
+                Color::GREEN,
+                Color::BLUE,
+                Color::PURPLE,
+            ]),
+        }
+    }
+}
+
+/// The whole application model: the displayed [`Time`] together with the
+/// editable [`Theme`] that paints it.
+#[derive(Clone, Data, Lens)]
+struct AppState {
+    time: Time,
+    theme: Theme,
+    /// Whether the clock is in live ticking mode and the second hand is
+    /// sweeping. Lives in the model so [`ClockFace`] knows whether to add a
+    /// sub-second fraction to the drawn time.
+    live: bool,
+    /// Whether the time-picker overlay is showing instead of the main face.
+    picking: bool,
+    /// The time as it was when the picker opened, so Cancel can restore it
+    /// after [`ClockFace`] has edited `time` in place during a drag.
+    picker_backup: Time,
 }
 
 #[derive(Debug, Clone)]
@@ -72,6 +146,7 @@ fn value(input: &str) -> Result<u8, ValidationError> {
 
 struct HoursFormatter;
 struct MinutesFormatter;
+struct SecondsFormatter;
 
 impl Formatter<u8> for HoursFormatter {
     fn format(&self, value: &u8) -> String {
@@ -101,6 +176,66 @@ impl Formatter<u8> for MinutesFormatter {
     }
 }
 
+impl Formatter<u8> for SecondsFormatter {
+    fn format(&self, value: &u8) -> String {
+        format(value)
+    }
+
+    fn validate_partial_input(&self, input: &str, _sel: &Selection) -> Validation {
+        validate_partial_input(input, 60)
+    }
+
+    fn value(&self, input: &str) -> Result<u8, ValidationError> {
+        value(input)
+    }
+}
+
+/// Renders a [`Color`] as a `#RRGGBB` hex string, ignoring its alpha channel.
+fn color_to_hex(color: &Color) -> String {
+    let (r, g, b, _) = color.as_rgba8();
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+/// Parses a `#RRGGBB` (or bare `RRGGBB`) hex string into a [`Color`].
+fn color_from_hex(input: &str) -> Option<Color> {
+    let hex = input.strip_prefix('#').unwrap_or(input);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::rgb8(r, g, b))
+}
+
+/// Edits a theme [`Color`] through the same `#RRGGBB` text entry used for the
+/// time fields, so the editor panel stays consistent with the rest of the UI.
+struct ColorFormatter;
+
+impl Formatter<Color> for ColorFormatter {
+    fn format(&self, value: &Color) -> String {
+        color_to_hex(value)
+    }
+
+    fn validate_partial_input(&self, input: &str, _sel: &Selection) -> Validation {
+        if input.is_empty() || color_from_hex(input).is_some() {
+            Validation::success()
+        } else {
+            Validation::failure(InputValidationError::new(
+                "Input must be a #RRGGBB hex color.",
+            ))
+        }
+    }
+
+    fn value(&self, input: &str) -> Result<Color, ValidationError> {
+        color_from_hex(input).ok_or_else(|| {
+            ValidationError::new(InputValidationError::new(
+                "Input must be a #RRGGBB hex color.",
+            ))
+        })
+    }
+}
+
 fn decrease_hours(data: &mut Time) {
     if data.hours == 0 {
         data.hours = 23;
@@ -135,11 +270,30 @@ fn increase_minutes(data: &mut Time) {
     }
 }
 
+fn decrease_seconds(data: &mut Time) {
+    if data.seconds == 0 {
+        data.seconds = 59;
+        decrease_minutes(data)
+    } else {
+        data.seconds -= 1;
+    }
+}
+
+fn increase_seconds(data: &mut Time) {
+    if data.seconds == 59 {
+        data.seconds = 0;
+        increase_minutes(data)
+    } else {
+        data.seconds += 1;
+    }
+}
+
 #[test]
 fn decrease_hours_decreases_hours() {
     let mut time = Time {
         hours: 12,
         minutes: 0,
+        seconds: 0,
     };
     decrease_hours(&mut time);
     assert_eq!(time.hours, 11);
@@ -150,6 +304,7 @@ fn decrease_hours_moves_to_23_from_0() {
     let mut time = Time {
         hours: 0,
         minutes: 0,
+        seconds: 0,
     };
     decrease_hours(&mut time);
     assert_eq!(time.hours, 23);
@@ -160,6 +315,7 @@ fn increase_hours_decreases_hours() {
     let mut time = Time {
         hours: 12,
         minutes: 0,
+        seconds: 0,
     };
     increase_hours(&mut time);
     assert_eq!(time.hours, 13);
@@ -170,6 +326,7 @@ fn increase_hours_moves_to_0_from_23() {
     let mut time = Time {
         hours: 23,
         minutes: 0,
+        seconds: 0,
     };
     increase_hours(&mut time);
     assert_eq!(time.hours, 0);
@@ -180,6 +337,7 @@ fn increase_minutes_increases_minutes() {
     let mut time = Time {
         hours: 12,
         minutes: 30,
+        seconds: 0,
     };
     increase_minutes(&mut time);
     assert_eq!(time.hours, 12);
@@ -191,6 +349,7 @@ fn increase_minutes_increases_minutes_and_hours() {
     let mut time = Time {
         hours: 12,
         minutes: 59,
+        seconds: 0,
     };
     increase_minutes(&mut time);
     assert_eq!(time.hours, 13);
@@ -202,6 +361,7 @@ fn decrease_minutes_decreases_minutes() {
     let mut time = Time {
         hours: 12,
         minutes: 30,
+        seconds: 0,
     };
     decrease_minutes(&mut time);
     assert_eq!(time.hours, 12);
@@ -213,163 +373,901 @@ fn decrease_minutes_decreases_minutes_and_hours() {
     let mut time = Time {
         hours: 12,
         minutes: 00,
+        seconds: 0,
     };
     decrease_minutes(&mut time);
     assert_eq!(time.hours, 11);
     assert_eq!(time.minutes, 59);
 }
 
-fn ui_builder() -> impl Widget<Time> {
-    // Text fields with hours and minutes
-    let valuetextbox_hours = ValueTextBox::new(TextBox::new(), HoursFormatter).lens(Time::hours);
-    let valuetextbox_minutes =
-        ValueTextBox::new(TextBox::new(), MinutesFormatter).lens(Time::minutes);
+#[test]
+fn increase_seconds_increases_seconds() {
+    let mut time = Time {
+        hours: 12,
+        minutes: 30,
+        seconds: 30,
+    };
+    increase_seconds(&mut time);
+    assert_eq!(time.minutes, 30);
+    assert_eq!(time.seconds, 31);
+}
 
-    // Buttons for increasing and decreasing hours and minutes
-    let increment_hours =
-        Button::new("+").on_click(|_ctx, data: &mut Time, _env| increase_hours(data));
-    let decrement_hours =
-        Button::new("-").on_click(|_ctx, data: &mut Time, _env| decrease_hours(data));
-    let increment_minutes =
-        Button::new("+").on_click(|_ctx, data: &mut Time, _env| increase_minutes(data));
-    let decrement_minutes =
-        Button::new("-").on_click(|_ctx, data: &mut Time, _env| decrease_minutes(data));
-
-    // Clock graphics
-    let clock = Painter::new(|ctx: &mut PaintCtx, data: &Time, _: &Env| {
-        let boundaries = ctx.size().to_rect();
-        let center = (boundaries.width() / 2.0, boundaries.height() / 2.0);
-        let circle = Circle::new(center, center.0.min(center.1));
-        ctx.fill(circle, &Color::WHITE);
+#[test]
+fn increase_seconds_increases_seconds_and_minutes() {
+    let mut time = Time {
+        hours: 12,
+        minutes: 30,
+        seconds: 59,
+    };
+    increase_seconds(&mut time);
+    assert_eq!(time.minutes, 31);
+    assert_eq!(time.seconds, 0);
+}
 
-        let orange = Color::rgb8(240, 128, 0);
+#[test]
+fn decrease_seconds_decreases_seconds() {
+    let mut time = Time {
+        hours: 12,
+        minutes: 30,
+        seconds: 30,
+    };
+    decrease_seconds(&mut time);
+    assert_eq!(time.minutes, 30);
+    assert_eq!(time.seconds, 29);
+}
 
-        let colors: [Color; 12] = [
-            Color::RED,
-            orange,
-            Color::YELLOW,
-            Color::GREEN,
-            Color::BLUE,
-            Color::PURPLE,
-            Color::RED,
-            orange,
-            Color::YELLOW,
-            Color::GREEN,
-            Color::BLUE,
-            Color::PURPLE,
-        ];
-
-        for (n, color) in colors.iter().enumerate() {
-            let circle_segment = CircleSegment::new(
-                center,
-                WINDOW_SIZE / 4. - WINDOW_SIZE / 40. * 2.,
-                WINDOW_SIZE / 4. - WINDOW_SIZE / 100.,
-                2. * PI / 12. * n as f64,
-                2. * PI / 12.,
-            );
-            ctx.fill(circle_segment, color);
+#[test]
+fn decrease_seconds_decreases_seconds_and_minutes() {
+    let mut time = Time {
+        hours: 12,
+        minutes: 30,
+        seconds: 0,
+    };
+    decrease_seconds(&mut time);
+    assert_eq!(time.minutes, 29);
+    assert_eq!(time.seconds, 59);
+}
+
+/// On-disk representation of a [`Theme`]: every color as a `#RRGGBB` string so
+/// the config file is human-editable.
+#[derive(Serialize, Deserialize)]
+struct StoredTheme {
+    background: String,
+    tick: String,
+    hour_hand: String,
+    minute_hand: String,
+    second_hand: String,
+    button_hover: String,
+    button_click: String,
+    palette: Vec<String>,
+}
+
+impl From<&Theme> for StoredTheme {
+    fn from(theme: &Theme) -> Self {
+        StoredTheme {
+            background: color_to_hex(&theme.background),
+            tick: color_to_hex(&theme.tick),
+            hour_hand: color_to_hex(&theme.hour_hand),
+            minute_hand: color_to_hex(&theme.minute_hand),
+            second_hand: color_to_hex(&theme.second_hand),
+            button_hover: color_to_hex(&theme.button_hover),
+            button_click: color_to_hex(&theme.button_click),
+            palette: theme.palette.iter().map(color_to_hex).collect(),
         }
+    }
+}
 
-        for n in 0..12 {
-            let x = (n as f64 / 12. * 2. * PI).cos();
-            let y = (n as f64 / 12. * 2. * PI).sin();
-            ctx.stroke(
-                Line::new(
-                    (
-                        x * (WINDOW_SIZE / 4. - WINDOW_SIZE / 40. * 2.) + (WINDOW_SIZE / 4.),
-                        y * (WINDOW_SIZE / 4. - WINDOW_SIZE / 40. * 2.) + (WINDOW_SIZE / 4.),
-                    ),
-                    (
-                        x * (WINDOW_SIZE / 4. - WINDOW_SIZE / 100.) + (WINDOW_SIZE / 4.),
-                        y * (WINDOW_SIZE / 4. - WINDOW_SIZE / 100.) + (WINDOW_SIZE / 4.),
-                    ),
-                ),
-                &Color::BLACK,
-                WINDOW_SIZE / 100.,
-            );
+impl StoredTheme {
+    /// Rebuilds a [`Theme`], falling back to [`Theme::default`] for any field
+    /// that fails to parse so a partially corrupt config never panics.
+    fn into_theme(self) -> Theme {
+        let default = Theme::default();
+        let color = |hex: &str, fallback: Color| color_from_hex(hex).unwrap_or(fallback);
+        // Keep the palette at the default length: use the stored hex where the
+        // config provides one and fall back to the default color otherwise, so
+        // a short or corrupt config can never leave fewer than the expected
+        // entries (the editor indexes all of them).
+        let palette: Vec<Color> = default
+            .palette
+            .iter()
+            .enumerate()
+            .map(|(n, fallback)| match self.palette.get(n) {
+                Some(hex) => color(hex, fallback.clone()),
+                None => fallback.clone(),
+            })
+            .collect();
+        Theme {
+            background: color(&self.background, default.background),
+            tick: color(&self.tick, default.tick),
+            hour_hand: color(&self.hour_hand, default.hour_hand),
+            minute_hand: color(&self.minute_hand, default.minute_hand),
+            second_hand: color(&self.second_hand, default.second_hand),
+            button_hover: color(&self.button_hover, default.button_hover),
+            button_click: color(&self.button_click, default.button_click),
+            palette: Arc::new(palette),
         }
-        for n in 0..60 {
-            let x = (n as f64 / 60. * 2. * PI).cos();
-            let y = (n as f64 / 60. * 2. * PI).sin();
-            ctx.stroke(
-                Line::new(
-                    (
-                        x * (WINDOW_SIZE / 4. - WINDOW_SIZE / 40.) + (WINDOW_SIZE / 4.),
-                        y * (WINDOW_SIZE / 4. - WINDOW_SIZE / 40.) + (WINDOW_SIZE / 4.),
-                    ),
-                    (
-                        x * (WINDOW_SIZE / 4. - WINDOW_SIZE / 100.) + (WINDOW_SIZE / 4.),
-                        y * (WINDOW_SIZE / 4. - WINDOW_SIZE / 100.) + (WINDOW_SIZE / 4.),
-                    ),
-                ),
-                &Color::BLACK,
-                WINDOW_SIZE / 200.,
-            );
+    }
+}
+
+/// Path of the theme config file (`clock_theme.json` in the user's home
+/// directory, or the working directory when `$HOME` is unset).
+fn theme_config_path() -> PathBuf {
+    let mut path = std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    path.push("clock_theme.json");
+    path
+}
+
+/// Loads a previously saved theme, or the default when none is stored.
+fn load_theme() -> Theme {
+    std::fs::read_to_string(theme_config_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str::<StoredTheme>(&contents).ok())
+        .map(StoredTheme::into_theme)
+        .unwrap_or_default()
+}
+
+/// Writes the theme to the config file so it survives restarts. Errors are
+/// swallowed: failing to persist a color choice should not crash the clock.
+fn save_theme(theme: &Theme) {
+    if let Ok(contents) = serde_json::to_string_pretty(&StoredTheme::from(theme)) {
+        let _ = std::fs::write(theme_config_path(), contents);
+    }
+}
+
+/// Persists the theme whenever it changes, keeping the config file in sync with
+/// live edits from the editor panel.
+struct PersistThemeController;
+
+impl<W: Widget<AppState>> Controller<AppState, W> for PersistThemeController {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut AppState,
+        env: &Env,
+    ) {
+        // The time-picker overlay confirms its selection by submitting
+        // [`PICK_TIME`]; commit it to the model and dismiss the overlay.
+        if let Event::Command(cmd) = event {
+            if let Some(time) = cmd.get(PICK_TIME) {
+                data.time = time.clone();
+                data.picking = false;
+                ctx.request_update();
+                return;
+            }
+        }
+        child.event(ctx, event, data, env);
+    }
+
+    fn update(
+        &mut self,
+        child: &mut W,
+        ctx: &mut UpdateCtx,
+        old_data: &AppState,
+        data: &AppState,
+        env: &Env,
+    ) {
+        if !old_data.theme.same(&data.theme) {
+            save_theme(&data.theme);
         }
+        child.update(ctx, old_data, data, env);
+    }
+}
+
+/// Overwrites `data` with the current local wall-clock time.
+fn set_to_now(data: &mut Time) {
+    let now = Local::now();
+    data.hours = now.hour() as u8;
+    data.minutes = now.minute() as u8;
+    data.seconds = now.second() as u8;
+}
 
-        let minutes_x = (data.minutes as f64 / 60. * 2. * PI - PI / 2.).cos();
-        let minutes_y = (data.minutes as f64 / 60. * 2. * PI - PI / 2.).sin();
-
-        let hours_x = (((data.hours as f64) % 12. + data.minutes as f64 / 60.) / 12. * 2. * PI
-            - PI / 2.)
-            .cos();
-        let hours_y = (((data.hours as f64) % 12. + data.minutes as f64 / 60.) / 12. * 2. * PI
-            - PI / 2.)
-            .sin();
-
-        for n in 0..12 {
-            let text_layout = ctx
-                .text()
-                .new_text_layout(format!("{}", n + 1))
-                .font(druid::piet::FontFamily::SYSTEM_UI, WINDOW_SIZE * 0.03)
-                .text_color(Color::BLACK)
-                .build()
-                .unwrap();
-
-            let text_size = text_layout.size();
-
-            let x = (n as f64 / 12. * 2. * PI - PI / 2. + 1. / 6. * PI).cos();
-            let y = (n as f64 / 12. * 2. * PI - PI / 2. + 1. / 6. * PI).sin();
-            let text_position = (
-                x * (WINDOW_SIZE / 40. * 7.25) - text_size.width / 2. + (WINDOW_SIZE / 4.),
-                y * (WINDOW_SIZE / 40. * 7.25) - text_size.height / 2. + (WINDOW_SIZE / 4.),
-            );
-
-            ctx.draw_text(&text_layout, text_position);
+/// Drives the clock face in "live" mode. `Painter` never sees events, so the
+/// animation loop lives in a `Controller` wrapped around the face instead.
+///
+/// Enabling live mode kicks off an animation-frame loop that, on every frame,
+/// refreshes the model from the system clock and repaints before requesting the
+/// next frame — so the whole-second value stays in step with the sweeping
+/// sub-second fraction without a separate timer.
+#[derive(Default)]
+struct LiveController;
+
+impl<W: Widget<AppState>> Controller<AppState, W> for LiveController {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut AppState,
+        env: &Env,
+    ) {
+        match event {
+            Event::Command(cmd) if cmd.is(TOGGLE_LIVE) => {
+                data.live = !data.live;
+                if data.live {
+                    set_to_now(&mut data.time);
+                    ctx.request_anim_frame();
+                }
+            }
+            // While live, refresh the whole-second value and repaint every frame
+            // so the integer second stays in step with the sweeping fraction.
+            Event::AnimFrame(_) if data.live => {
+                set_to_now(&mut data.time);
+                ctx.request_paint();
+                ctx.request_anim_frame();
+            }
+            _ => child.event(ctx, event, data, env),
         }
+    }
+}
 
-        ctx.stroke(
-            Line::new(
-                (WINDOW_SIZE / 4., WINDOW_SIZE / 4.),
-                (
-                    minutes_x * (WINDOW_SIZE / 40. * 6.5) + (WINDOW_SIZE / 4.),
-                    minutes_y * (WINDOW_SIZE / 40. * 6.5) + (WINDOW_SIZE / 4.),
-                ),
-            ),
-            &Color::BLACK,
-            WINDOW_SIZE / 100.,
+/// Drawing primitives the clock face needs, abstracted over the rendering
+/// backend so the same dial math can target druid's `PaintCtx` or an
+/// `embedded_graphics` `DrawTarget` (an OLED, a headless framebuffer, ...).
+/// Coordinates and radii are in backend pixels; `draw_text` centers the string
+/// on `center`.
+trait ClockCanvas {
+    fn fill_circle(&mut self, center: (f64, f64), radius: f64, color: &Color);
+    fn stroke_line(&mut self, from: (f64, f64), to: (f64, f64), color: &Color, width: f64);
+    fn fill_segment(
+        &mut self,
+        center: (f64, f64),
+        inner_radius: f64,
+        outer_radius: f64,
+        start_angle: f64,
+        sweep_angle: f64,
+        color: &Color,
+    );
+    fn draw_text(&mut self, text: &str, center: (f64, f64), font_size: f64, color: &Color);
+}
+
+/// [`ClockCanvas`] over druid's piet-backed `PaintCtx` — the on-screen backend.
+struct PietCanvas<'a, 'b, 'c> {
+    ctx: &'a mut PaintCtx<'b, 'c>,
+}
+
+impl ClockCanvas for PietCanvas<'_, '_, '_> {
+    fn fill_circle(&mut self, center: (f64, f64), radius: f64, color: &Color) {
+        self.ctx.fill(Circle::new(center, radius), color);
+    }
+
+    fn stroke_line(&mut self, from: (f64, f64), to: (f64, f64), color: &Color, width: f64) {
+        self.ctx.stroke(Line::new(from, to), color, width);
+    }
+
+    fn fill_segment(
+        &mut self,
+        center: (f64, f64),
+        inner_radius: f64,
+        outer_radius: f64,
+        start_angle: f64,
+        sweep_angle: f64,
+        color: &Color,
+    ) {
+        self.ctx.fill(
+            CircleSegment::new(center, outer_radius, inner_radius, start_angle, sweep_angle),
+            color,
         );
-        ctx.stroke(
-            Line::new(
-                (WINDOW_SIZE / 4., WINDOW_SIZE / 4.),
-                (
-                    hours_x * (WINDOW_SIZE / 40. * 3.25) + (WINDOW_SIZE / 4.),
-                    hours_y * (WINDOW_SIZE / 40. * 3.25) + (WINDOW_SIZE / 4.),
-                ),
+    }
+
+    fn draw_text(&mut self, text: &str, center: (f64, f64), font_size: f64, color: &Color) {
+        let layout = self
+            .ctx
+            .text()
+            .new_text_layout(text.to_string())
+            .font(druid::piet::FontFamily::SYSTEM_UI, font_size)
+            .text_color(color.clone())
+            .build()
+            .unwrap();
+        let size = layout.size();
+        self.ctx.draw_text(
+            &layout,
+            (center.0 - size.width / 2., center.1 - size.height / 2.),
+        );
+    }
+}
+
+/// [`ClockCanvas`] over an `embedded_graphics` target, so the face can be driven
+/// onto a monochrome/color OLED (SSD1306/SSD1351) or a headless framebuffer,
+/// the way the raspi-oled project renders its clock. Kept behind the
+/// `embedded` feature since it pulls in the `embedded-graphics` dependency.
+#[cfg(feature = "embedded")]
+struct EmbeddedCanvas<'a, D> {
+    target: &'a mut D,
+}
+
+#[cfg(feature = "embedded")]
+impl<D> EmbeddedCanvas<'_, D> {
+    fn color(color: &Color) -> embedded_graphics::pixelcolor::Rgb888 {
+        let (r, g, b, _) = color.as_rgba8();
+        embedded_graphics::pixelcolor::Rgb888::new(r, g, b)
+    }
+
+    fn point(p: (f64, f64)) -> embedded_graphics::geometry::Point {
+        embedded_graphics::geometry::Point::new(p.0.round() as i32, p.1.round() as i32)
+    }
+}
+
+#[cfg(feature = "embedded")]
+impl<D> ClockCanvas for EmbeddedCanvas<'_, D>
+where
+    D: embedded_graphics::draw_target::DrawTarget<Color = embedded_graphics::pixelcolor::Rgb888>,
+{
+    fn fill_circle(&mut self, center: (f64, f64), radius: f64, color: &Color) {
+        use embedded_graphics::prelude::*;
+        let top_left = Self::point((center.0 - radius, center.1 - radius));
+        let diameter = (radius * 2.0).round() as u32;
+        let _ = embedded_graphics::primitives::Circle::new(top_left, diameter)
+            .into_styled(embedded_graphics::primitives::PrimitiveStyle::with_fill(Self::color(
+                color,
+            )))
+            .draw(self.target);
+    }
+
+    fn stroke_line(&mut self, from: (f64, f64), to: (f64, f64), color: &Color, width: f64) {
+        use embedded_graphics::prelude::*;
+        let _ = embedded_graphics::primitives::Line::new(Self::point(from), Self::point(to))
+            .into_styled(embedded_graphics::primitives::PrimitiveStyle::with_stroke(
+                Self::color(color),
+                width.round().max(1.0) as u32,
+            ))
+            .draw(self.target);
+    }
+
+    fn fill_segment(
+        &mut self,
+        center: (f64, f64),
+        _inner_radius: f64,
+        outer_radius: f64,
+        start_angle: f64,
+        sweep_angle: f64,
+        color: &Color,
+    ) {
+        use embedded_graphics::prelude::*;
+        // A filled sector from the center; the inner radius is ignored because a
+        // small OLED has no room for the thin ring the desktop face draws.
+        let top_left = Self::point((center.0 - outer_radius, center.1 - outer_radius));
+        let diameter = (outer_radius * 2.0).round() as u32;
+        let _ = embedded_graphics::primitives::Sector::new(
+            top_left,
+            diameter,
+            embedded_graphics::geometry::Angle::from_radians(start_angle as f32),
+            embedded_graphics::geometry::Angle::from_radians(sweep_angle as f32),
+        )
+        .into_styled(embedded_graphics::primitives::PrimitiveStyle::with_fill(Self::color(color)))
+        .draw(self.target);
+    }
+
+    fn draw_text(&mut self, text: &str, center: (f64, f64), _font_size: f64, color: &Color) {
+        use embedded_graphics::prelude::*;
+        let style = embedded_graphics::mono_font::MonoTextStyle::new(
+            &embedded_graphics::mono_font::ascii::FONT_6X10,
+            Self::color(color),
+        );
+        let text_style = embedded_graphics::text::TextStyleBuilder::new()
+            .alignment(embedded_graphics::text::Alignment::Center)
+            .baseline(embedded_graphics::text::Baseline::Middle)
+            .build();
+        let _ = embedded_graphics::text::Text::with_text_style(
+            text,
+            Self::point(center),
+            style,
+            text_style,
+        )
+        .draw(self.target);
+    }
+}
+
+/// Render one frame of the clock onto an `embedded_graphics` draw target, the
+/// entry point used when building with `--features embedded` to drive an OLED
+/// or a headless framebuffer from the same [`draw_clock`] dial math.
+#[cfg(feature = "embedded")]
+fn render_embedded<D>(target: &mut D, size: Size, theme: &Theme, time: &Time, sub_second: f64)
+where
+    D: embedded_graphics::draw_target::DrawTarget<Color = embedded_graphics::pixelcolor::Rgb888>,
+{
+    let mut canvas = EmbeddedCanvas { target };
+    draw_clock(&mut canvas, size, theme, time, sub_second);
+}
+
+/// Exercises the whole embedded draw path by rendering one frame into an
+/// in-memory [`Framebuffer`] — the real consumer that makes `render_embedded`
+/// (and the backend behind it) compiled and run by `cargo build`/`cargo run
+/// --features embedded`, without needing a physical panel attached. Per-board
+/// firmware swaps the framebuffer for its own `DrawTarget`.
+#[cfg(feature = "embedded")]
+fn render_embedded_frame() {
+    use embedded_graphics::pixelcolor::Rgb888;
+    use embedded_graphics::prelude::PixelColor;
+
+    const SIDE: usize = 64;
+    let mut framebuffer = embedded_graphics::framebuffer::Framebuffer::<
+        Rgb888,
+        <Rgb888 as PixelColor>::Raw,
+        embedded_graphics::pixelcolor::raw::LittleEndian,
+        SIDE,
+        SIDE,
+        { embedded_graphics::framebuffer::buffer_size::<Rgb888>(SIDE, SIDE) },
+    >::new();
+    render_embedded(
+        &mut framebuffer,
+        Size::new(SIDE as f64, SIDE as f64),
+        &Theme::default(),
+        &Time {
+            hours: 12,
+            minutes: 0,
+            seconds: 0,
+        },
+        0.0,
+    );
+}
+
+/// Draws the whole dial — background, color segments, hour/minute ticks,
+/// numerals and the three hands — through [`ClockCanvas`] so it is identical on
+/// every backend. Every radius, length, stroke width and the font size is a
+/// fraction of `radius = size.min_side() / 2`, so the face fills whatever
+/// `size` the widget is laid out at instead of a fixed window size.
+fn draw_clock<C: ClockCanvas>(
+    canvas: &mut C,
+    size: Size,
+    theme: &Theme,
+    time: &Time,
+    sub_second: f64,
+) {
+    let cx = size.width / 2.;
+    let cy = size.height / 2.;
+    let center = (cx, cy);
+    let radius = size.min_side() / 2.;
+    canvas.fill_circle(center, radius, &theme.background);
+
+    for (n, color) in theme.palette.iter().enumerate() {
+        canvas.fill_segment(
+            center,
+            radius * 0.8,
+            radius * 0.96,
+            2. * PI / 12. * n as f64,
+            2. * PI / 12.,
+            color,
+        );
+    }
+
+    for n in 0..12 {
+        let x = (n as f64 / 12. * 2. * PI).cos();
+        let y = (n as f64 / 12. * 2. * PI).sin();
+        canvas.stroke_line(
+            (x * radius * 0.8 + cx, y * radius * 0.8 + cy),
+            (x * radius * 0.96 + cx, y * radius * 0.96 + cy),
+            &theme.tick,
+            radius * 0.04,
+        );
+    }
+    for n in 0..60 {
+        let x = (n as f64 / 60. * 2. * PI).cos();
+        let y = (n as f64 / 60. * 2. * PI).sin();
+        canvas.stroke_line(
+            (x * radius * 0.9 + cx, y * radius * 0.9 + cy),
+            (x * radius * 0.96 + cx, y * radius * 0.96 + cy),
+            &theme.tick,
+            radius * 0.02,
+        );
+    }
+
+    let seconds_fraction = time.seconds as f64 + sub_second;
+    let seconds_x = (seconds_fraction / 60. * 2. * PI - PI / 2.).cos();
+    let seconds_y = (seconds_fraction / 60. * 2. * PI - PI / 2.).sin();
+
+    let minutes_x = (time.minutes as f64 / 60. * 2. * PI - PI / 2.).cos();
+    let minutes_y = (time.minutes as f64 / 60. * 2. * PI - PI / 2.).sin();
+
+    let hours_x = (((time.hours as f64) % 12. + time.minutes as f64 / 60.) / 12. * 2. * PI
+        - PI / 2.)
+        .cos();
+    let hours_y = (((time.hours as f64) % 12. + time.minutes as f64 / 60.) / 12. * 2. * PI
+        - PI / 2.)
+        .sin();
+
+    for n in 0..12 {
+        let x = (n as f64 / 12. * 2. * PI - PI / 2. + 1. / 6. * PI).cos();
+        let y = (n as f64 / 12. * 2. * PI - PI / 2. + 1. / 6. * PI).sin();
+        canvas.draw_text(
+            &format!("{}", n + 1),
+            (x * radius * 0.725 + cx, y * radius * 0.725 + cy),
+            radius * 0.12,
+            &theme.tick,
+        );
+    }
+
+    canvas.stroke_line(
+        center,
+        (minutes_x * radius * 0.65 + cx, minutes_y * radius * 0.65 + cy),
+        &theme.minute_hand,
+        radius * 0.04,
+    );
+    canvas.stroke_line(
+        center,
+        (hours_x * radius * 0.325 + cx, hours_y * radius * 0.325 + cy),
+        &theme.hour_hand,
+        radius * 0.04,
+    );
+    canvas.stroke_line(
+        center,
+        (seconds_x * radius * 0.7 + cx, seconds_y * radius * 0.7 + cy),
+        &theme.second_hand,
+        radius / 75.,
+    );
+}
+
+/// The hand the user has grabbed while dragging on the dial.
+#[derive(Clone, Copy, PartialEq)]
+enum Hand {
+    Hour,
+    Minute,
+}
+
+/// The analog clock face as a self-contained widget. Splitting it out of the
+/// inline `Painter` closure lets it both draw itself and, unlike `Painter`,
+/// receive pointer events for hit-testing — the foundation the draggable hands
+/// and the embeddable time-picker overlay build on.
+struct ClockFace {
+    /// The hand currently being dragged, if any.
+    dragging: Option<Hand>,
+}
+
+impl ClockFace {
+    fn new() -> Self {
+        ClockFace { dragging: None }
+    }
+
+    /// Fraction of a full turn, in `[0, 1)`, from the dial center to `point`,
+    /// with 12 o'clock as zero and increasing clockwise — the inverse of the
+    /// angle math used to place the hands.
+    fn point_to_fraction(size: Size, point: Point) -> f64 {
+        let center = Point::new(size.width / 2.0, size.height / 2.0);
+        let angle = (point.y - center.y).atan2(point.x - center.x) + PI / 2.0;
+        angle.rem_euclid(2.0 * PI) / (2.0 * PI)
+    }
+
+    /// Maps a point on the dial to the minute (0..60) its angle points at.
+    fn point_to_minute(size: Size, point: Point) -> u8 {
+        ((Self::point_to_fraction(size, point) * 60.0).round() as i64).rem_euclid(60) as u8
+    }
+
+    /// Maps a point on the dial to the hour (0..12) its angle points at.
+    fn point_to_hour(size: Size, point: Point) -> u8 {
+        ((Self::point_to_fraction(size, point) * 12.0).round() as i64).rem_euclid(12) as u8
+    }
+
+    /// Decides which hand the user grabbed from how far the press lands from the
+    /// center: presses inside the shorter hour hand's reach grab the hour hand,
+    /// presses further out grab the minute hand.
+    fn hand_at(size: Size, point: Point) -> Hand {
+        let center = Point::new(size.width / 2.0, size.height / 2.0);
+        let radius = size.width.min(size.height) / 2.0;
+        let distance = (point - center).hypot();
+        // Halfway between the hour-hand tip (0.325 r) and minute-hand tip (0.65 r).
+        if distance < radius * 0.49 {
+            Hand::Hour
+        } else {
+            Hand::Minute
+        }
+    }
+
+    /// Applies a drag of `hand` at `point` to `time`, snapping to the nearest
+    /// minute or hour while preserving the AM/PM half of the day.
+    fn apply_drag(size: Size, point: Point, hand: Hand, time: &mut Time) {
+        match hand {
+            Hand::Minute => time.minutes = Self::point_to_minute(size, point),
+            Hand::Hour => {
+                let hour = Self::point_to_hour(size, point);
+                time.hours = if time.hours >= 12 { 12 + hour } else { hour };
+            }
+        }
+    }
+}
+
+impl Widget<AppState> for ClockFace {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut AppState, _env: &Env) {
+        match event {
+            Event::MouseDown(mouse) => {
+                let hand = ClockFace::hand_at(ctx.size(), mouse.pos);
+                self.dragging = Some(hand);
+                ctx.set_active(true);
+                ClockFace::apply_drag(ctx.size(), mouse.pos, hand, &mut data.time);
+                ctx.request_paint();
+            }
+            Event::MouseMove(mouse) if ctx.is_active() => {
+                if let Some(hand) = self.dragging {
+                    ClockFace::apply_drag(ctx.size(), mouse.pos, hand, &mut data.time);
+                    ctx.request_paint();
+                }
+            }
+            Event::MouseUp(_) if ctx.is_active() => {
+                self.dragging = None;
+                ctx.set_active(false);
+            }
+            _ => {}
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        _ctx: &mut LifeCycleCtx,
+        _event: &LifeCycle,
+        _data: &AppState,
+        _env: &Env,
+    ) {
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &AppState, data: &AppState, _env: &Env) {
+        if !old_data.same(data) {
+            ctx.request_paint();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &AppState,
+        _env: &Env,
+    ) -> Size {
+        bc.max()
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, state: &AppState, _env: &Env) {
+        // While live, sweep the second hand with the real clock's sub-second
+        // fraction so it stays in phase with `time.seconds`; when not sweeping
+        // use `0.0` so the hand points exactly at the entered second.
+        let sub_second = if state.live {
+            Local::now().nanosecond() as f64 / 1_000_000_000.0
+        } else {
+            0.0
+        };
+        let size = ctx.size();
+        let mut canvas = PietCanvas { ctx };
+        draw_clock(&mut canvas, size, &state.theme, &state.time, sub_second);
+    }
+}
+
+/// Horizontal/vertical padding baked around a [`ThemedButton`]'s label.
+const BUTTON_PADDING: f64 = 8.0;
+
+/// A push button whose fill is tinted from the active [`Theme`]: `button_click`
+/// while pressed, `button_hover` when the pointer is over it, and the theme
+/// background otherwise. This is what makes those two color fields actually
+/// drive the rendered controls instead of sitting unused in the model.
+struct ThemedButton {
+    label: String,
+    action: Box<dyn Fn(&mut EventCtx, &mut AppState, &Env)>,
+}
+
+impl ThemedButton {
+    fn new(
+        label: impl Into<String>,
+        action: impl Fn(&mut EventCtx, &mut AppState, &Env) + 'static,
+    ) -> Self {
+        ThemedButton {
+            label: label.into(),
+            action: Box::new(action),
+        }
+    }
+}
+
+impl Widget<AppState> for ThemedButton {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut AppState, env: &Env) {
+        match event {
+            Event::MouseDown(_) => {
+                ctx.set_active(true);
+                ctx.request_paint();
+            }
+            Event::MouseUp(_) => {
+                if ctx.is_active() {
+                    ctx.set_active(false);
+                    ctx.request_paint();
+                    if ctx.is_hot() {
+                        (self.action)(ctx, data, env);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &AppState, _env: &Env) {
+        if let LifeCycle::HotChanged(_) = event {
+            ctx.request_paint();
+        }
+    }
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _old: &AppState, _data: &AppState, _env: &Env) {}
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &AppState,
+        _env: &Env,
+    ) -> Size {
+        let text_size = ctx
+            .text()
+            .new_text_layout(self.label.clone())
+            .font(druid::piet::FontFamily::SYSTEM_UI, 14.0)
+            .build()
+            .unwrap()
+            .size();
+        bc.constrain(Size::new(
+            text_size.width + 2. * BUTTON_PADDING,
+            text_size.height + 2. * BUTTON_PADDING,
+        ))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &AppState, _env: &Env) {
+        let size = ctx.size();
+        let rect = size.to_rect();
+        let fill = if ctx.is_active() {
+            &data.theme.button_click
+        } else if ctx.is_hot() {
+            &data.theme.button_hover
+        } else {
+            &data.theme.background
+        };
+        ctx.fill(rect, fill);
+        ctx.stroke(rect, &data.theme.tick, 1.0);
+
+        let layout = ctx
+            .text()
+            .new_text_layout(self.label.clone())
+            .font(druid::piet::FontFamily::SYSTEM_UI, 14.0)
+            .text_color(data.theme.tick.clone())
+            .build()
+            .unwrap();
+        let text_size = layout.size();
+        ctx.draw_text(
+            &layout,
+            (
+                (size.width - text_size.width) / 2.,
+                (size.height - text_size.height) / 2.,
             ),
-            &Color::BLACK,
-            WINDOW_SIZE / 100.,
         );
+    }
+}
+
+/// Lens onto a single entry of the theme [`palette`], so each swatch gets its
+/// own hex editor row without reshaping the `Arc<Vec<Color>>` storage.
+struct PaletteColor(usize);
+
+impl Lens<Theme, Color> for PaletteColor {
+    fn with<V, F: FnOnce(&Color) -> V>(&self, theme: &Theme, f: F) -> V {
+        f(&theme.palette[self.0])
+    }
+
+    fn with_mut<V, F: FnOnce(&mut Color) -> V>(&self, theme: &mut Theme, f: F) -> V {
+        let mut color = theme.palette[self.0].clone();
+        let result = f(&mut color);
+        Arc::make_mut(&mut theme.palette)[self.0] = color;
+        result
+    }
+}
+
+/// A reusable, embeddable time-picker overlay built around [`ClockFace`].
+///
+/// Modelled on iced_aw's `time_picker`: it renders the analog face with
+/// cancel/confirm buttons so any druid UI can pop it up to select a time. The
+/// face is interactive (clicking and dragging set the hands) and the chosen
+/// [`Time`] is emitted via the [`PICK_TIME`] command on confirm, which
+/// [`PersistThemeController`] commits to the model and uses to dismiss the
+/// overlay. Because [`ClockFace`] edits `time` in place as the user drags,
+/// the pre-open value is snapshotted into `picker_backup` when the overlay
+/// opens; Cancel restores it so the picker is atomic.
+fn time_picker() -> impl Widget<AppState> {
+    let cancel = ThemedButton::new("Cancel", |_ctx, data: &mut AppState, _env| {
+        data.time = data.picker_backup.clone();
+        data.picking = false;
+    });
+    let confirm = ThemedButton::new("Confirm", |ctx, data: &mut AppState, _env| {
+        ctx.submit_command(PICK_TIME.with(data.time.clone()))
+    });
+
+    Flex::column()
+        .with_child(
+            SizedBox::new(ClockFace::new())
+                .width(WINDOW_SIZE / 2.)
+                .height(WINDOW_SIZE / 2.),
+        )
+        .with_spacer(4.0)
+        .with_child(Flex::row().with_child(cancel).with_spacer(4.0).with_child(confirm))
+}
+
+/// A single labeled `#RRGGBB` entry for one theme color, lensed onto the field
+/// reached by `lens`. Reused for every row of the editor panel.
+fn color_editor(
+    label: &str,
+    lens: impl Lens<Theme, Color> + 'static,
+) -> impl Widget<AppState> {
+    Flex::row()
+        .with_child(druid::widget::Label::new(label.to_string()))
+        .with_spacer(4.0)
+        .with_child(ValueTextBox::new(TextBox::new(), ColorFormatter).lens(lens))
+        .lens(AppState::theme)
+}
+
+/// The live theme editor: one hex entry per color field, wired so edits repaint
+/// the face immediately and are persisted by [`PersistThemeController`].
+fn theme_editor() -> impl Widget<AppState> {
+    let mut col = Flex::column()
+        .with_child(color_editor("Background", Theme::background))
+        .with_child(color_editor("Ticks", Theme::tick))
+        .with_child(color_editor("Hour hand", Theme::hour_hand))
+        .with_child(color_editor("Minute hand", Theme::minute_hand))
+        .with_child(color_editor("Second hand", Theme::second_hand))
+        .with_child(color_editor("Button hover", Theme::button_hover))
+        .with_child(color_editor("Button click", Theme::button_click));
+    for n in 0..Theme::default().palette.len() {
+        col.add_child(color_editor(&format!("Segment {}", n + 1), PaletteColor(n)));
+    }
+    col
+}
+
+/// The main editing view: the live face, the manual controls, and the theme
+/// editor. Shown whenever the time-picker overlay is not up.
+fn main_view() -> impl Widget<AppState> {
+    // Text fields with hours, minutes and seconds
+    let valuetextbox_hours = ValueTextBox::new(TextBox::new(), HoursFormatter)
+        .lens(AppState::time.then(Time::hours));
+    let valuetextbox_minutes = ValueTextBox::new(TextBox::new(), MinutesFormatter)
+        .lens(AppState::time.then(Time::minutes));
+    let valuetextbox_seconds = ValueTextBox::new(TextBox::new(), SecondsFormatter)
+        .lens(AppState::time.then(Time::seconds));
+
+    // Buttons for increasing and decreasing hours, minutes and seconds, tinted by the
+    // active theme via [`ThemedButton`].
+    let increment_hours =
+        ThemedButton::new("+", |_ctx, data: &mut AppState, _env| increase_hours(&mut data.time));
+    let decrement_hours =
+        ThemedButton::new("-", |_ctx, data: &mut AppState, _env| decrease_hours(&mut data.time));
+    let increment_minutes = ThemedButton::new("+", |_ctx, data: &mut AppState, _env| {
+        increase_minutes(&mut data.time)
+    });
+    let decrement_minutes = ThemedButton::new("-", |_ctx, data: &mut AppState, _env| {
+        decrease_minutes(&mut data.time)
+    });
+    let increment_seconds = ThemedButton::new("+", |_ctx, data: &mut AppState, _env| {
+        increase_seconds(&mut data.time)
+    });
+    let decrement_seconds = ThemedButton::new("-", |_ctx, data: &mut AppState, _env| {
+        decrease_seconds(&mut data.time)
+    });
+
+    // Clock graphics, now a self-contained, hit-testable widget.
+    let clock = ClockFace::new();
+
+    // Toggles the live ticking mode on and off.
+    let toggle_live = ThemedButton::new("Live", |ctx, _data: &mut AppState, _env| {
+        ctx.submit_command(TOGGLE_LIVE)
+    });
+
+    // Pops up the interactive time-picker overlay, snapshotting the current
+    // time so Cancel can restore it.
+    let pick = ThemedButton::new("Pick time", |_ctx, data: &mut AppState, _env| {
+        data.picker_backup = data.time.clone();
+        data.picking = true;
     });
 
     // Creating a layout using the graphics described above
     Flex::column()
         .with_child(
-            SizedBox::new(clock)
+            SizedBox::new(clock.controller(LiveController::default()))
                 .width(WINDOW_SIZE / 2.)
                 .height(WINDOW_SIZE / 2.),
         )
         .with_spacer(4.0)
+        .with_child(toggle_live)
+        .with_spacer(4.0)
+        .with_child(pick)
+        .with_spacer(4.0)
         .with_child(
             Flex::row()
                 .with_child(
@@ -383,19 +1281,59 @@ fn ui_builder() -> impl Widget<Time> {
                         .with_child(increment_minutes)
                         .with_child(valuetextbox_minutes)
                         .with_child(decrement_minutes),
+                )
+                .with_child(
+                    Flex::column()
+                        .with_child(increment_seconds)
+                        .with_child(valuetextbox_seconds)
+                        .with_child(decrement_seconds),
                 ),
         )
+        .with_spacer(4.0)
+        .with_child(theme_editor())
+}
+
+/// Switches between the main editing view and the time-picker overlay based on
+/// [`AppState::picking`], with theme persistence wrapped around both.
+fn ui_builder() -> impl Widget<AppState> {
+    ViewSwitcher::new(
+        |data: &AppState, _env| data.picking,
+        |picking, _data, _env| {
+            if *picking {
+                Box::new(time_picker())
+            } else {
+                Box::new(main_view())
+            }
+        },
+    )
+    .controller(PersistThemeController)
 }
 
 fn main() {
+    // When built with `--features embedded`, render one frame through the
+    // embedded backend so that draw path is compiled and exercised too.
+    #[cfg(feature = "embedded")]
+    render_embedded_frame();
+
     let main_window = WindowDesc::new(ui_builder())
         .window_size((WINDOW_SIZE * 0.6, WINDOW_SIZE * 0.6))
         .title("Clock");
     AppLauncher::with_window(main_window)
         .log_to_console()
-        .launch(Time {
-            hours: 12,
-            minutes: 0,
+        .launch(AppState {
+            time: Time {
+                hours: 12,
+                minutes: 0,
+                seconds: 0,
+            },
+            theme: load_theme(),
+            live: false,
+            picking: false,
+            picker_backup: Time {
+                hours: 12,
+                minutes: 0,
+                seconds: 0,
+            },
         })
         .unwrap()
 }